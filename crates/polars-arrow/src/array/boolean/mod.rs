@@ -5,6 +5,7 @@ use super::{Array, Splitable};
 use crate::array::iterator::NonNullValuesIter;
 use crate::bitmap::utils::{BitmapIter, ZipValidity};
 use crate::bitmap::{Bitmap, MutableBitmap};
+use crate::buffer::Buffer;
 use crate::compute::utils::{combine_validities_and, combine_validities_or};
 use crate::datatypes::{ArrowDataType, PhysicalType};
 use crate::trusted_len::TrustedLen;
@@ -81,6 +82,54 @@ impl BooleanArray {
         })
     }
 
+    /// Tries to create a new [`BooleanArray`] from an already bit-packed, LSB-first slice of
+    /// bytes, without re-bitpacking it.
+    ///
+    /// This is intended for callers that already hold an Arrow-encoded boolean bitmap (e.g.
+    /// received over FFI, IPC, or custom IO) and want to hand it straight to a [`BooleanArray`],
+    /// instead of round-tripping through [`BooleanArray::from_trusted_len_values_iter`].
+    /// # Errors
+    /// This function errors iff:
+    /// * `ceil(len, 8) > bytes.len()`
+    /// * The validity is not `None` and its length is different from `len`
+    /// * The `dtype`'s [`PhysicalType`] is not equal to [`PhysicalType::Boolean`]
+    pub fn try_from_packed_slice(
+        dtype: ArrowDataType,
+        bytes: &[u8],
+        len: usize,
+        validity: Option<Bitmap>,
+    ) -> PolarsResult<Self> {
+        Self::from_packed_buffer(dtype, bytes.to_vec().into(), 0, len, validity)
+    }
+
+    /// Creates a new [`BooleanArray`] by wrapping an already bit-packed, LSB-first [`Buffer`]
+    /// of bytes, without copying or re-bitpacking it.
+    ///
+    /// `offset_bits` is the number of bits (not bytes) to skip from the start of `buffer`
+    /// before the first value of the array. This mirrors the low-end [`BooleanArray::try_new`]
+    /// path but additionally lets callers supply an explicit bit offset, as produced by Arrow
+    /// FFI/IPC.
+    /// # Errors
+    /// This function errors iff:
+    /// * `ceil(offset_bits + len, 8) > buffer.len()`
+    /// * The validity is not `None` and its length is different from `len`
+    /// * The `dtype`'s [`PhysicalType`] is not equal to [`PhysicalType::Boolean`]
+    pub fn from_packed_buffer(
+        dtype: ArrowDataType,
+        buffer: Buffer<u8>,
+        offset_bits: usize,
+        len: usize,
+        validity: Option<Bitmap>,
+    ) -> PolarsResult<Self> {
+        let needed_bytes = (offset_bits + len).div_ceil(8);
+        if needed_bytes > buffer.len() {
+            polars_bail!(ComputeError: "packed buffer of {} bytes is too small to hold {len} bits at bit offset {offset_bits}", buffer.len())
+        }
+
+        let values = Bitmap::try_new(buffer, offset_bits + len)?.sliced(offset_bits, len);
+        Self::try_new(dtype, values, validity)
+    }
+
     /// Alias to `Self::try_new().unwrap()`
     pub fn new(dtype: ArrowDataType, values: Bitmap, validity: Option<Bitmap>) -> Self {
         Self::try_new(dtype, values, validity).unwrap()
@@ -234,6 +283,41 @@ impl BooleanArray {
         self.values = values.into();
     }
 
+    /// Applies a binary function `f` to the values of this array and the values of `rhs`,
+    /// cloning the values of `self` iff they are being shared with others, and writing the
+    /// result in place of `self`'s values.
+    ///
+    /// This is an API to use clone-on-write for binary kernels (e.g. AND/OR/XOR, boolean
+    /// comparisons): when `self`'s values are uniquely owned, `f` can combine them with
+    /// `rhs` without allocating a new buffer.
+    /// # Implementation
+    /// This function is `O(f)` if `self`'s data is not being shared, and `O(N) + O(f)`
+    /// if it is being shared (since it results in a `O(N)` memcopy).
+    /// # Panics
+    /// This function panics if `self` and `rhs` have different lengths, or if the function
+    /// modifies the length of the [`MutableBitmap`].
+    pub fn apply_binary_mut<F: Fn(&mut MutableBitmap, &Bitmap)>(
+        &mut self,
+        rhs: &BooleanArray,
+        f: F,
+    ) {
+        assert_eq!(
+            self.len(),
+            rhs.len(),
+            "arrays must have the same length to apply a binary operation"
+        );
+        let values = std::mem::take(&mut self.values);
+        let mut values = values.make_mut();
+        f(&mut values, rhs.values());
+        assert_eq!(
+            values.len(),
+            rhs.len(),
+            "the function cannot change the length of the `MutableBitmap`"
+        );
+        self.values = values.into();
+        self.validity = combine_validities_and(self.validity(), rhs.validity());
+    }
+
     /// Try to convert this [`BooleanArray`] to a [`MutableBooleanArray`]
     pub fn into_mut(self) -> Either<Self, MutableBooleanArray> {
         use Either::*;
@@ -439,3 +523,97 @@ impl From<Bitmap> for BooleanArray {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apply_binary_mut_ands_values_and_combines_validity() {
+        let mut lhs = BooleanArray::try_new(
+            ArrowDataType::Boolean,
+            Bitmap::from([true, true, false, false]),
+            Some(Bitmap::from([true, true, true, false])),
+        )
+        .unwrap();
+        let rhs = BooleanArray::try_new(
+            ArrowDataType::Boolean,
+            Bitmap::from([true, false, true, true]),
+            Some(Bitmap::from([true, false, true, true])),
+        )
+        .unwrap();
+
+        lhs.apply_binary_mut(&rhs, |lhs_values, rhs_values| {
+            for i in 0..lhs_values.len() {
+                let value = lhs_values.get(i) && rhs_values.get_bit(i);
+                lhs_values.set(i, value);
+            }
+        });
+
+        assert_eq!(
+            lhs.values_iter().collect::<Vec<_>>(),
+            vec![true, false, false, false]
+        );
+        assert_eq!(lhs.validity(), Some(&Bitmap::from([true, false, true, false])));
+    }
+
+    #[test]
+    #[should_panic(expected = "arrays must have the same length")]
+    fn apply_binary_mut_panics_on_length_mismatch() {
+        let mut lhs = BooleanArray::from_slice([true, false]);
+        let rhs = BooleanArray::from_slice([true, false, true]);
+
+        lhs.apply_binary_mut(&rhs, |_, _| {});
+    }
+
+    /// Packs `bits` into LSB-first bytes, as produced by Arrow IPC/FFI.
+    fn pack_lsb(bits: &[bool]) -> Vec<u8> {
+        let mut bytes = vec![0u8; bits.len().div_ceil(8)];
+        for (i, &bit) in bits.iter().enumerate() {
+            if bit {
+                bytes[i / 8] |= 1 << (i % 8);
+            }
+        }
+        bytes
+    }
+
+    #[test]
+    fn from_packed_buffer_respects_mid_byte_offset() {
+        // offset_bits = 3 is not byte-aligned, and offset_bits + len spans a byte boundary.
+        let bits = [
+            true, true, true, false, true, false, true, true, false, true, false, true, false,
+        ];
+        let bytes = pack_lsb(&bits);
+        let len = bits.len() - 3;
+
+        let array =
+            BooleanArray::from_packed_buffer(ArrowDataType::Boolean, bytes.into(), 3, len, None)
+                .unwrap();
+
+        assert_eq!(array.values_iter().collect::<Vec<_>>(), bits[3..].to_vec());
+    }
+
+    #[test]
+    fn from_packed_buffer_errors_on_insufficient_bytes() {
+        let bytes = vec![0u8; 1];
+
+        let result =
+            BooleanArray::from_packed_buffer(ArrowDataType::Boolean, bytes.into(), 3, 10, None);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn try_from_packed_slice_matches_packed_bits() {
+        let bits = [
+            true, false, true, true, false, true, false, true, true, false,
+        ];
+        let bytes = pack_lsb(&bits);
+
+        let array =
+            BooleanArray::try_from_packed_slice(ArrowDataType::Boolean, &bytes, bits.len(), None)
+                .unwrap();
+
+        assert_eq!(array.values_iter().collect::<Vec<_>>(), bits.to_vec());
+    }
+}
@@ -0,0 +1,324 @@
+use polars_error::{PolarsResult, polars_bail};
+
+use crate::array::BooleanArray;
+use crate::bitmap::MutableBitmap;
+use crate::datatypes::{ArrowDataType, PhysicalType};
+use crate::trusted_len::TrustedLen;
+
+/// The Arrow's equivalent to `Vec<Option<bool>>`, but with at most `1/4` of its size.
+/// Converting a [`MutableBooleanArray`] into a [`BooleanArray`] is `O(1)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MutableBooleanArray {
+    dtype: ArrowDataType,
+    values: MutableBitmap,
+    validity: Option<MutableBitmap>,
+}
+
+impl Default for MutableBooleanArray {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<MutableBooleanArray> for BooleanArray {
+    fn from(other: MutableBooleanArray) -> Self {
+        BooleanArray::new(
+            other.dtype,
+            other.values.into(),
+            other.validity.map(|x| x.into()),
+        )
+    }
+}
+
+impl MutableBooleanArray {
+    /// Creates a new empty [`MutableBooleanArray`].
+    pub fn new() -> Self {
+        Self::try_new(ArrowDataType::Boolean, MutableBitmap::new(), None).unwrap()
+    }
+
+    /// Creates a new [`MutableBooleanArray`] with capacity for `capacity` values.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            dtype: ArrowDataType::Boolean,
+            values: MutableBitmap::with_capacity(capacity),
+            validity: None,
+        }
+    }
+
+    /// The canonical method to create a [`MutableBooleanArray`] out of low-end APIs.
+    /// # Errors
+    /// This function errors iff:
+    /// * The validity is not `None` and its length is different from `values`'s length
+    /// * The `dtype`'s [`PhysicalType`] is not equal to [`PhysicalType::Boolean`].
+    pub fn try_new(
+        dtype: ArrowDataType,
+        values: MutableBitmap,
+        validity: Option<MutableBitmap>,
+    ) -> PolarsResult<Self> {
+        if validity
+            .as_ref()
+            .is_some_and(|validity| validity.len() != values.len())
+        {
+            polars_bail!(ComputeError: "validity mask length must match the number of values")
+        }
+
+        if dtype.to_physical_type() != PhysicalType::Boolean {
+            polars_bail!(ComputeError: "MutableBooleanArray can only be initialized with a DataType whose physical type is Boolean")
+        }
+
+        Ok(Self {
+            dtype,
+            values,
+            validity,
+        })
+    }
+
+    /// Returns the number of values in this array.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns whether this array is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Reserves `additional` slots.
+    pub fn reserve(&mut self, additional: usize) {
+        self.values.reserve(additional);
+        if let Some(validity) = &mut self.validity {
+            validity.reserve(additional);
+        }
+    }
+
+    /// Shrinks the capacity of this array to fit its length.
+    pub fn shrink_to_fit(&mut self) {
+        self.values.shrink_to_fit();
+        if let Some(validity) = &mut self.validity {
+            validity.shrink_to_fit();
+        }
+    }
+
+    fn init_validity(&mut self) {
+        let mut validity = MutableBitmap::with_capacity(self.values.capacity());
+        validity.extend_constant(self.len(), true);
+        validity.set(self.len() - 1, false);
+        self.validity = Some(validity);
+    }
+
+    /// Pushes a new value onto this array.
+    pub fn push(&mut self, value: Option<bool>) {
+        match value {
+            Some(value) => {
+                self.values.push(value);
+                if let Some(validity) = &mut self.validity {
+                    validity.push(true)
+                }
+            },
+            None => {
+                self.values.push(false);
+                match &mut self.validity {
+                    Some(validity) => validity.push(false),
+                    None => self.init_validity(),
+                }
+            },
+        }
+    }
+
+    /// Creates a new [`MutableBooleanArray`] from an [`TrustedLen`] of `bool`.
+    #[inline]
+    pub fn from_trusted_len_values_iter<I: TrustedLen<Item = bool>>(iterator: I) -> Self {
+        Self {
+            dtype: ArrowDataType::Boolean,
+            values: MutableBitmap::from_trusted_len_iter(iterator),
+            validity: None,
+        }
+    }
+
+    /// Creates a new [`MutableBooleanArray`] from an [`TrustedLen`] of `bool`.
+    ///
+    /// # Safety
+    /// The iterator must be [`TrustedLen`](https://doc.rust-lang.org/std/iter/trait.TrustedLen.html).
+    /// I.e. that `size_hint().1` correctly reports its length.
+    #[inline]
+    pub unsafe fn from_trusted_len_values_iter_unchecked<I: Iterator<Item = bool>>(
+        iterator: I,
+    ) -> Self {
+        Self {
+            dtype: ArrowDataType::Boolean,
+            values: MutableBitmap::from_trusted_len_iter_unchecked(iterator),
+            validity: None,
+        }
+    }
+
+    /// Creates a new [`MutableBooleanArray`] from a slice of `bool`.
+    #[inline]
+    pub fn from_slice<P: AsRef<[bool]>>(slice: P) -> Self {
+        Self::from_trusted_len_values_iter(slice.as_ref().iter().copied())
+    }
+
+    /// Creates a [`MutableBooleanArray`] from an iterator of trusted length.
+    ///
+    /// # Safety
+    /// The iterator must be [`TrustedLen`](https://doc.rust-lang.org/std/iter/trait.TrustedLen.html).
+    /// I.e. that `size_hint().1` correctly reports its length.
+    #[inline]
+    pub unsafe fn from_trusted_len_iter_unchecked<I, P>(iterator: I) -> Self
+    where
+        P: std::borrow::Borrow<bool>,
+        I: Iterator<Item = Option<P>>,
+    {
+        let mut array = Self::with_capacity(iterator.size_hint().1.unwrap_or(0));
+        for item in iterator {
+            array.push(item.map(|x| *x.borrow()));
+        }
+        array
+    }
+
+    /// Creates a [`MutableBooleanArray`] from a [`TrustedLen`].
+    #[inline]
+    pub fn from_trusted_len_iter<I, P>(iterator: I) -> Self
+    where
+        P: std::borrow::Borrow<bool>,
+        I: TrustedLen<Item = Option<P>>,
+    {
+        unsafe { Self::from_trusted_len_iter_unchecked(iterator) }
+    }
+
+    /// Creates a [`MutableBooleanArray`] from a fallible iterator of trusted length.
+    ///
+    /// # Safety
+    /// The iterator must be [`TrustedLen`](https://doc.rust-lang.org/std/iter/trait.TrustedLen.html).
+    /// I.e. that `size_hint().1` correctly reports its length.
+    #[inline]
+    pub unsafe fn try_from_trusted_len_iter_unchecked<E, I, P>(iterator: I) -> Result<Self, E>
+    where
+        P: std::borrow::Borrow<bool>,
+        I: Iterator<Item = Result<Option<P>, E>>,
+    {
+        let mut array = Self::with_capacity(iterator.size_hint().1.unwrap_or(0));
+        for item in iterator {
+            array.push(item?.map(|x| *x.borrow()));
+        }
+        Ok(array)
+    }
+
+    /// Creates a [`MutableBooleanArray`] from a [`TrustedLen`].
+    #[inline]
+    pub fn try_from_trusted_len_iter<E, I, P>(iterator: I) -> Result<Self, E>
+    where
+        P: std::borrow::Borrow<bool>,
+        I: TrustedLen<Item = Result<Option<P>, E>>,
+    {
+        unsafe { Self::try_from_trusted_len_iter_unchecked(iterator) }
+    }
+}
+
+/// A validity-free, growable analogue of [`MutableBooleanArray`] for hot paths that are
+/// statically known to never produce nulls (e.g. comparison kernels, `is_null`/`is_not_null`,
+/// range masks).
+///
+/// Unlike [`MutableBooleanArray`], this type tracks only a [`MutableBitmap`] of values and
+/// never allocates a validity bitmap, avoiding the per-element validity branch and the
+/// `Option<Bitmap>` bookkeeping entirely when callers can guarantee totality.
+#[derive(Default, Clone, Debug, PartialEq)]
+pub struct MutableBooleanValuesArray {
+    values: MutableBitmap,
+}
+
+impl MutableBooleanValuesArray {
+    /// Creates a new empty [`MutableBooleanValuesArray`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new [`MutableBooleanValuesArray`] with capacity for `capacity` values.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            values: MutableBitmap::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the number of values in this array.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns whether this array is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Reserves `additional` slots.
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self.values.reserve(additional);
+    }
+
+    /// Shrinks the capacity of this array to fit its length.
+    pub fn shrink_to_fit(&mut self) {
+        self.values.shrink_to_fit();
+    }
+
+    /// Pushes a new value onto this array.
+    #[inline]
+    pub fn push(&mut self, value: bool) {
+        self.values.push(value);
+    }
+
+    /// Pushes a new value onto this array, without checking that there is enough capacity.
+    ///
+    /// # Safety
+    /// Caller must ensure that the array has enough capacity to support this push.
+    #[inline]
+    pub unsafe fn push_unchecked(&mut self, value: bool) {
+        self.values.push_unchecked(value);
+    }
+
+    /// Extends this array with `len` repetitions of `value`.
+    #[inline]
+    pub fn extend_constant(&mut self, len: usize, value: bool) {
+        self.values.extend_constant(len, value);
+    }
+
+    /// Extends this array from a [`TrustedLen`] of `bool`.
+    #[inline]
+    pub fn extend_from_trusted_len_iter<I: TrustedLen<Item = bool>>(&mut self, iterator: I) {
+        self.values.extend_from_trusted_len_iter(iterator);
+    }
+}
+
+impl From<MutableBooleanValuesArray> for BooleanArray {
+    /// Converts this [`MutableBooleanValuesArray`] into a [`BooleanArray`] with `validity: None`
+    /// in `O(1)`.
+    fn from(other: MutableBooleanValuesArray) -> Self {
+        BooleanArray::new(ArrowDataType::Boolean, other.values.into(), None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn push_extend_and_convert_round_trip() {
+        let mut array = MutableBooleanValuesArray::with_capacity(5);
+        array.push(true);
+        array.push(false);
+        array.extend_constant(3, true);
+
+        assert_eq!(array.len(), 5);
+        assert!(!array.is_empty());
+
+        let array: BooleanArray = array.into();
+        assert_eq!(array.validity(), None);
+        assert_eq!(
+            array.values_iter().collect::<Vec<_>>(),
+            vec![true, false, true, true, true]
+        );
+    }
+}